@@ -26,10 +26,16 @@ fn integration_preview_chat_mode_contains_turn_labels() {
         machine_codex_home: None,
         machine_exec_prefix: None,
         started_at: "2026-01-01T00:00:00Z".to_string(),
+        ended_at: String::new(),
         modified_epoch: 123,
+        size_bytes: 0,
+        has_backup: false,
+        incomplete: false,
+        mixed_cwds: false,
         event_count: 3,
         user_message_count: 1,
         assistant_message_count: 1,
+        tool_call_count: 0,
         search_blob: "one two".to_string(),
     };
 